@@ -1,10 +1,94 @@
 use anyhow::{Context, Result, anyhow};
-use norad::{Anchor, Codepoints, Contour, ContourPoint, Glyph, Name, PointType};
+use norad::{
+    AffineTransform, Anchor, Codepoints, Component, Contour, ContourPoint, Glyph, Name, PointType,
+};
 use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 use svgtypes::{Length, LengthUnit, SimplePathSegment, SimplifyingPathParser, Transform};
 
+/// How quadratic Bézier segments (`Q`/`T`) are emitted into the glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuadMode {
+    /// Emit the control point as an off-curve and the endpoint as a
+    /// `qcurve` on-curve point, matching GLIF's native quadratic support.
+    #[default]
+    QCurve,
+    /// Up-convert to an equivalent cubic using
+    /// `CP1 = P0 + 2/3·(Q − P0)` and `CP2 = P2 + 2/3·(Q − P2)`, emitting
+    /// two off-curves followed by a `curve` on-curve point.
+    Cubic,
+}
+
+/// Join style used when converting stroked outlines to filled contours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Extend the outer edges until they meet, falling back to [`LineJoin::Bevel`]
+    /// when the miter would exceed the miter limit.
+    #[default]
+    Miter,
+    /// Round the corner with an arc centred on the vertex.
+    Round,
+    /// Cut the corner off with a straight edge.
+    Bevel,
+}
+
+/// Cap style used at the open ends of a stroked contour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// End the stroke flush with the endpoint.
+    #[default]
+    Butt,
+    /// Extend a half-circle beyond the endpoint.
+    Round,
+    /// Extend a half-width square beyond the endpoint.
+    Square,
+}
+
+/// Stroke styling used by the stroke-to-fill subsystem. The join/cap/miter
+/// values act as defaults and are overridden by the matching SVG presentation
+/// attributes (`stroke-linejoin`, `stroke-linecap`, `stroke-miterlimit`).
+#[derive(Debug, Clone, Copy)]
+pub struct StrokeStyle {
+    /// Corner join style.
+    pub join: LineJoin,
+    /// End cap style for open contours.
+    pub cap: LineCap,
+    /// Ratio at which a miter join degrades to a bevel.
+    pub miter_limit: f32,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        // Matches the SVG presentation-attribute defaults.
+        Self {
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+        }
+    }
+}
+
+/// A streaming sink for outline segments parsed from an SVG path.
+///
+/// Conversion drives a pen as it walks the path data rather than building a
+/// `norad::Glyph` directly, so integrators can feed SVG outlines straight into
+/// their own geometry structures (a kurbo `BezPath`, a rasterizer, a different
+/// font backend) without the intermediate glyph allocation. Coordinates are
+/// reported in final UFO space (scaled, with the baseline Y-flip applied).
+pub trait OutlinePen {
+    /// Begin a new contour at `(x, y)`.
+    fn move_to(&mut self, x: f64, y: f64);
+    /// Draw a straight line to `(x, y)`.
+    fn line_to(&mut self, x: f64, y: f64);
+    /// Draw a quadratic Bézier through control point `(cx, cy)` to `(x, y)`.
+    fn quad_to(&mut self, cx: f64, cy: f64, x: f64, y: f64);
+    /// Draw a cubic Bézier through `(c1x, c1y)` and `(c2x, c2y)` to `(x, y)`.
+    fn curve_to(&mut self, c1x: f64, c1y: f64, c2x: f64, c2y: f64, x: f64, y: f64);
+    /// Close the current contour.
+    fn close(&mut self);
+}
+
 /// Configuration for SVG to GLIF conversion
 pub struct ConversionConfig {
     /// Units per em (typically 1000 or 2048)
@@ -15,6 +99,20 @@ pub struct ConversionConfig {
     pub unicode: Option<String>,
     /// Optional name for the glyph, if not given, filename will be used.
     pub name: Option<String>,
+    /// How quadratic Bézier segments are emitted.
+    pub quad_mode: QuadMode,
+    /// When set, stroked SVG shapes are converted to filled contours using
+    /// the given default [`StrokeStyle`].
+    pub stroke: Option<StrokeStyle>,
+    /// When `true`, contour winding is normalized after conversion so that
+    /// outer contours and holes carry the orientation UFO/GLIF fill expects.
+    pub normalize_winding: bool,
+    /// Overrides the SVG's own `height` attribute when computing `scale` and
+    /// the baseline shift. [`FontConfig`] sets this to the same value for
+    /// every glyph in a batch so they all share one coordinate frame —
+    /// required for `<use>` components to resolve correctly when source
+    /// files declare different dimensions.
+    pub svg_height_override: Option<f32>,
 }
 
 impl ConversionConfig {
@@ -25,6 +123,10 @@ impl ConversionConfig {
             descent,
             unicode: None,
             name: None,
+            quad_mode: QuadMode::default(),
+            stroke: None,
+            normalize_winding: false,
+            svg_height_override: None,
         }
     }
 
@@ -39,6 +141,35 @@ impl ConversionConfig {
         self.name = Some(name);
         self
     }
+
+    /// Select how quadratic Bézier segments are emitted (native qcurve
+    /// points or up-converted cubics).
+    pub fn with_quad_mode(mut self, quad_mode: QuadMode) -> Self {
+        self.quad_mode = quad_mode;
+        self
+    }
+
+    /// Enable stroke-to-fill conversion with the given default style.
+    pub fn with_stroke(mut self, stroke: StrokeStyle) -> Self {
+        self.stroke = Some(stroke);
+        self
+    }
+
+    /// Normalize contour winding after conversion so counters punch out
+    /// correctly (outer contours counterclockwise, holes clockwise, matching
+    /// ufo2ft/fontmake's default CFF/PostScript convention).
+    pub fn with_normalize_winding(mut self, normalize_winding: bool) -> Self {
+        self.normalize_winding = normalize_winding;
+        self
+    }
+
+    /// Override the SVG's own `height` attribute when computing `scale` and
+    /// the baseline shift, so this glyph shares a coordinate frame with
+    /// others converted under the same override (see [`FontConfig`]).
+    pub fn with_svg_height_override(mut self, svg_height: f32) -> Self {
+        self.svg_height_override = Some(svg_height);
+        self
+    }
 }
 
 /// Convert an SVG file to a GLIF glyph
@@ -81,7 +212,10 @@ pub fn convert_svg_string_to_glyph(
 
     // Get SVG dimensions
     let svg_width = parse_length(root.attribute("width").unwrap_or("100"))?;
-    let svg_height = parse_length(root.attribute("height").unwrap_or("100"))?;
+    let file_svg_height = parse_length(root.attribute("height").unwrap_or("100"))?;
+    // A batch conversion overrides this with a font-wide value so every glyph
+    // shares one coordinate frame; otherwise trust the file's own attribute.
+    let svg_height = config.svg_height_override.unwrap_or(file_svg_height);
 
     // Scale to font units
     let scale = config.em_size / svg_height;
@@ -116,9 +250,15 @@ pub fn convert_svg_string_to_glyph(
         svg_height,
         config.descent,
         scale,
+        config.quad_mode,
+        config.stroke.as_ref(),
         &Transform::default(),
     )?;
 
+    if config.normalize_winding {
+        normalize_winding(&mut glyph);
+    }
+
     Ok(glyph)
 }
 
@@ -150,6 +290,160 @@ pub fn convert_svg_to_glif_file(
     Ok(())
 }
 
+/// Font-wide settings shared by every glyph when assembling a UFO from a
+/// directory of SVG drawings. Unlike per-file [`ConversionConfig::em_size`] /
+/// [`ConversionConfig::descent`], these place all glyphs in one coordinate
+/// system: every source SVG must declare `svg_viewport` as its `height`
+/// attribute, since that value (not each file's own attribute) drives
+/// `scale` and the baseline shift for every glyph in the run.
+pub struct FontConfig {
+    /// Units per em for the whole font (typically 1000 or 2048).
+    pub units_per_em: f32,
+    /// Ascender metric in font units.
+    pub ascender: f32,
+    /// Descender metric in font units (conventionally negative).
+    pub descender: f32,
+    /// The `height` (in SVG user units) every source file is expected to
+    /// share. Used in place of each file's own `height` attribute so `scale`
+    /// and the baseline shift are identical across glyphs, which keeps
+    /// `<use>` components correct when resolved across files.
+    pub svg_viewport: f32,
+    /// How quadratic Bézier segments are emitted.
+    pub quad_mode: QuadMode,
+    /// When set, stroked shapes are converted to filled contours.
+    pub stroke: Option<StrokeStyle>,
+    /// When `true`, contour winding is normalized after conversion.
+    pub normalize_winding: bool,
+}
+
+impl FontConfig {
+    /// Create a new font configuration from the core metrics. `svg_viewport`
+    /// is the `height` every source SVG in the directory is expected to
+    /// declare.
+    pub fn new(units_per_em: f32, ascender: f32, descender: f32, svg_viewport: f32) -> Self {
+        Self {
+            units_per_em,
+            ascender,
+            descender,
+            svg_viewport,
+            quad_mode: QuadMode::default(),
+            stroke: None,
+            normalize_winding: false,
+        }
+    }
+
+    /// Select how quadratic Bézier segments are emitted.
+    pub fn with_quad_mode(mut self, quad_mode: QuadMode) -> Self {
+        self.quad_mode = quad_mode;
+        self
+    }
+
+    /// Enable stroke-to-fill conversion with the given default style.
+    pub fn with_stroke(mut self, stroke: StrokeStyle) -> Self {
+        self.stroke = Some(stroke);
+        self
+    }
+
+    /// Normalize contour winding after conversion.
+    pub fn with_normalize_winding(mut self, normalize_winding: bool) -> Self {
+        self.normalize_winding = normalize_winding;
+        self
+    }
+
+    /// Build the per-glyph [`ConversionConfig`] for `name`/`unicode` that shares
+    /// this font's coordinate system.
+    fn glyph_config(&self, name: String, unicode: Option<String>) -> ConversionConfig {
+        // `ConversionConfig::descent` is read in SVG units, before `scale` is
+        // applied, so the font-unit descender has to be converted into the
+        // shared `svg_viewport` frame (the per-glyph `descent` shifts the
+        // drawing down so the SVG's bottom edge sits that far below the
+        // baseline; the font descender is negative, so negate it first).
+        let descent_svg = -self.descender * self.svg_viewport / self.units_per_em;
+        let mut config = ConversionConfig::new(self.units_per_em, descent_svg)
+            .with_svg_height_override(self.svg_viewport)
+            .with_quad_mode(self.quad_mode)
+            .with_normalize_winding(self.normalize_winding)
+            .with_name(name);
+        if let Some(unicode) = unicode {
+            config = config.with_unicode(unicode);
+        }
+        if let Some(stroke) = self.stroke {
+            config = config.with_stroke(stroke);
+        }
+        config
+    }
+}
+
+/// Derive a glyph name and optional codepoint (hex) from an SVG file stem.
+/// A `uniXXXX` stem maps to the corresponding Unicode codepoint, mirroring the
+/// AGL `uni` naming convention.
+fn glyph_name_and_codepoint(stem: &str) -> (String, Option<String>) {
+    if let Some(hex) = stem.strip_prefix("uni") {
+        let valid = hex.len() >= 4
+            && hex.len() % 2 == 0
+            && hex.chars().all(|c| c.is_ascii_hexdigit());
+        if valid {
+            return (stem.to_string(), Some(hex.to_string()));
+        }
+    }
+    (stem.to_string(), None)
+}
+
+/// Assemble a complete UFO font from a directory of `.svg` glyph drawings.
+///
+/// Each `.svg` file becomes one glyph: the file stem is the glyph name, and a
+/// `uniXXXX` stem also supplies the Unicode codepoint. Every glyph shares the
+/// coordinate system described by `config`, and `<use>` references resolve
+/// against the other glyphs converted in the same run.
+pub fn convert_svg_dir_to_ufo(dir: &Path, out: &Path, config: &FontConfig) -> Result<()> {
+    let mut svg_files: Vec<std::path::PathBuf> = fs::read_dir(dir)
+        .context("reading svg directory")?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case("svg"))
+        })
+        .collect();
+    // Deterministic glyph order.
+    svg_files.sort();
+
+    let mut font = norad::Font::new();
+    font.font_info.units_per_em = Some((config.units_per_em as f64).try_into()?);
+    font.font_info.ascender = Some((config.ascender as f64).into());
+    font.font_info.descender = Some((config.descender as f64).into());
+
+    let mut glyph_order: Vec<Name> = Vec::with_capacity(svg_files.len());
+    for path in &svg_files {
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow!("invalid svg filename: {}", path.display()))?;
+        let (name, unicode) = glyph_name_and_codepoint(stem);
+        let glyph_config = config.glyph_config(name, unicode);
+        let glyph = convert_svg_to_glyph(path, &glyph_config)
+            .with_context(|| format!("converting {}", path.display()))?;
+        glyph_order.push(glyph.name().clone());
+        font.default_layer_mut().insert_glyph(glyph);
+    }
+
+    font.lib
+        .insert("public.glyphOrder".into(), glyph_order_value(&glyph_order));
+
+    font.save(out).context("writing ufo")?;
+    Ok(())
+}
+
+/// Encode the glyph order as the `public.glyphOrder` plist array.
+fn glyph_order_value(order: &[Name]) -> plist::Value {
+    plist::Value::Array(
+        order
+            .iter()
+            .map(|name| plist::Value::String(name.to_string()))
+            .collect(),
+    )
+}
+
 fn multiply(ts1: &Transform, ts2: &Transform) -> Transform {
     Transform {
         a: ts1.a * ts2.a + ts1.c * ts2.b,
@@ -167,12 +461,15 @@ fn apply_transform(transform: &Transform, x: f32, y: f32) -> (f32, f32) {
     (new_x, new_y)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_svg_node(
     node: &roxmltree::Node,
     glyph: &mut Glyph,
     svg_height: f32,
     descent: f32,
     scale: f32,
+    quad_mode: QuadMode,
+    stroke: Option<&StrokeStyle>,
     parent_transform: &Transform,
 ) -> Result<()> {
     // Compute current transform
@@ -186,13 +483,36 @@ fn process_svg_node(
     match node.tag_name().name() {
         "path" => {
             if let Some(d) = node.attribute("d") {
-                let contours =
-                    process_path_data(d, svg_height, descent, scale, &current_transform)?;
-                if !glyph.contours.is_empty() {
-                    glyph.contours.extend(contours);
+                let parsed = process_path_data(
+                    d,
+                    svg_height,
+                    descent,
+                    scale,
+                    quad_mode,
+                    &current_transform,
+                )?;
+
+                let contours = if let Some(style) = stroke.filter(|_| node_has_stroke(node)) {
+                    let style = resolve_stroke_style(node, style);
+                    let width = node
+                        .attribute("stroke-width")
+                        .and_then(|s| parse_length(s).ok())
+                        .unwrap_or(1.0)
+                        * scale;
+                    let mut out = Vec::new();
+                    for (contour, closed) in &parsed {
+                        out.extend(stroke_contour(contour, *closed, width, &style));
+                    }
+                    // Keep the painted fill too unless the element is fill-less.
+                    if node.attribute("fill") != Some("none") {
+                        out.extend(parsed.into_iter().map(|(c, _)| c));
+                    }
+                    out
                 } else {
-                    glyph.contours = contours;
-                }
+                    parsed.into_iter().map(|(c, _)| c).collect()
+                };
+
+                glyph.contours.extend(contours);
             }
         }
         "text" => {
@@ -202,6 +522,13 @@ fn process_svg_node(
                 glyph.anchors.push(anchor);
             }
         }
+        "use" => {
+            if let Some(component) =
+                process_use_as_component(node, svg_height, descent, scale, &current_transform)?
+            {
+                glyph.components.push(component);
+            }
+        }
         "g" | "svg" => {
             // Process children
             for child in node.children() {
@@ -212,6 +539,8 @@ fn process_svg_node(
                         svg_height,
                         descent,
                         scale,
+                        quad_mode,
+                        stroke,
                         &current_transform,
                     )?;
                 }
@@ -227,6 +556,8 @@ fn process_svg_node(
                         svg_height,
                         descent,
                         scale,
+                        quad_mode,
+                        stroke,
                         &current_transform,
                     )?;
                 }
@@ -246,14 +577,36 @@ fn parse_length(length_str: &str) -> Result<f32> {
 }
 
 fn process_path_data(
+    path_data: &str,
+    svg_height: f32,
+    descent: f32,
+    scale: f32,
+    quad_mode: QuadMode,
+    transform: &Transform,
+) -> Result<Vec<(Contour, bool)>> {
+    let mut pen = GlyphPen::new(quad_mode);
+    drive_path_pen(path_data, svg_height, descent, scale, transform, &mut pen)?;
+    Ok(pen.into_contours())
+}
+
+/// Parse SVG path data and drive `pen` with the resulting outline segments,
+/// reporting coordinates in final UFO space (scaled, with the baseline Y-flip).
+///
+/// This is the streaming core behind [`convert_svg_to_glyph`]: callers with
+/// their own [`OutlinePen`] can convert a path without building a
+/// `norad::Glyph`.
+pub fn drive_path_pen<P: OutlinePen + ?Sized>(
     path_data: &str,
     svg_height: f32,
     descent: f32,
     scale: f32,
     transform: &Transform,
-) -> Result<Vec<Contour>> {
-    let mut contours = Vec::new();
-    let mut current_contour: Vec<ContourPoint> = Vec::new();
+    pen: &mut P,
+) -> Result<()> {
+    let map = |x: f64, y: f64| {
+        let (tx, ty) = apply_transform(transform, x as f32, y as f32);
+        svg_to_ufo(tx, ty, svg_height, descent, scale)
+    };
 
     // Use SimplifyingPathParser - all coordinates are absolute!
     // This automatically handles:
@@ -266,33 +619,12 @@ fn process_path_data(
 
         match segment {
             SimplePathSegment::MoveTo { x, y } => {
-                // Start new contour
-                if !current_contour.is_empty() {
-                    contours.push(Contour::new(current_contour, None));
-                    current_contour = Vec::new();
-                }
-                let (tx, ty) = apply_transform(transform, x as f32, y as f32);
-                let (ux, uy) = svg_to_ufo(tx, ty, svg_height, descent, scale);
-                current_contour.push(ContourPoint::new(
-                    ux,
-                    uy,
-                    PointType::Curve,
-                    true,
-                    None,
-                    None,
-                ));
+                let (ux, uy) = map(x, y);
+                pen.move_to(ux, uy);
             }
             SimplePathSegment::LineTo { x, y } => {
-                let (tx, ty) = apply_transform(transform, x as f32, y as f32);
-                let (ux, uy) = svg_to_ufo(tx, ty, svg_height, descent, scale);
-                current_contour.push(ContourPoint::new(
-                    ux,
-                    uy,
-                    PointType::Line,
-                    false,
-                    None,
-                    None,
-                ));
+                let (ux, uy) = map(x, y);
+                pen.line_to(ux, uy);
             }
             SimplePathSegment::CurveTo {
                 x1,
@@ -302,73 +634,139 @@ fn process_path_data(
                 x,
                 y,
             } => {
-                // Add two off-curve control points
-                let (tx1, ty1) = apply_transform(transform, x1 as f32, y1 as f32);
-                let (ux1, uy1) = svg_to_ufo(tx1, ty1, svg_height, descent, scale);
-                current_contour.push(ContourPoint::new(
-                    ux1,
-                    uy1,
-                    PointType::OffCurve,
-                    false,
-                    None,
-                    None,
-                ));
-
-                let (tx2, ty2) = apply_transform(transform, x2 as f32, y2 as f32);
-                let (ux2, uy2) = svg_to_ufo(tx2, ty2, svg_height, descent, scale);
-                current_contour.push(ContourPoint::new(
-                    ux2,
-                    uy2,
-                    PointType::OffCurve,
-                    false,
-                    None,
-                    None,
-                ));
-
-                // Add on-curve point
-                let (tx, ty) = apply_transform(transform, x as f32, y as f32);
-                let (ux, uy) = svg_to_ufo(tx, ty, svg_height, descent, scale);
-                current_contour.push(ContourPoint::new(
-                    ux,
-                    uy,
-                    PointType::Curve,
-                    true,
-                    None,
-                    None,
-                ));
+                let (uc1x, uc1y) = map(x1, y1);
+                let (uc2x, uc2y) = map(x2, y2);
+                let (ux, uy) = map(x, y);
+                pen.curve_to(uc1x, uc1y, uc2x, uc2y, ux, uy);
             }
-            SimplePathSegment::Quadratic { .. } => {
-                // Skip quadratic curves as they're not supported in UFO/GLIF
-                // If needed, they could be converted to cubic Bezier curves
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                let (ucx, ucy) = map(x1, y1);
+                let (ux, uy) = map(x, y);
+                pen.quad_to(ucx, ucy, ux, uy);
             }
-            SimplePathSegment::ClosePath => {
-                // Finish current contour
-                if !current_contour.is_empty() {
-                    contours.push(Contour::new(current_contour, None));
-                    current_contour = Vec::new();
+            SimplePathSegment::ClosePath => pen.close(),
+        }
+    }
+
+    Ok(())
+}
+
+/// The [`OutlinePen`] that builds `norad` contours — the reference sink used by
+/// the GLIF conversion path.
+struct GlyphPen {
+    quad_mode: QuadMode,
+    contours: Vec<(Contour, bool)>,
+    current: Vec<ContourPoint>,
+    // Last on-curve point, needed to up-convert quadratics to cubics.
+    current_point: (f64, f64),
+}
+
+impl GlyphPen {
+    fn new(quad_mode: QuadMode) -> Self {
+        Self {
+            quad_mode,
+            contours: Vec::new(),
+            current: Vec::new(),
+            current_point: (0.0, 0.0),
+        }
+    }
+
+    /// Flush the built contours, dropping any final point that duplicates the
+    /// contour's first point.
+    fn into_contours(mut self) -> Vec<(Contour, bool)> {
+        // Add any remaining contour (left open).
+        if !self.current.is_empty() {
+            let contour = std::mem::take(&mut self.current);
+            self.contours.push((Contour::new(contour, None), false));
+        }
+
+        // Remove duplicate last point if it matches the first point
+        for (contour, _) in &mut self.contours {
+            if contour.points.len() > 1 {
+                let first = &contour.points[0];
+                let last = &contour.points[contour.points.len() - 1];
+
+                if first.x == last.x && first.y == last.y {
+                    let popped = contour.points.pop();
+                    // The popped point closed the last segment; if that
+                    // segment was a quadratic, a single off-curve now
+                    // precedes `points[0]` and the start point must be
+                    // retyped to `QCurve` to match (move_to always types it
+                    // `Curve`, which is wrong once the closing curve is
+                    // quadratic rather than cubic).
+                    if popped.is_some_and(|p| p.typ == PointType::QCurve)
+                        && contour.points[contour.points.len() - 1].typ == PointType::OffCurve
+                    {
+                        contour.points[0].typ = PointType::QCurve;
+                    }
                 }
             }
         }
+
+        self.contours
     }
+}
 
-    // Add any remaining contour
-    if !current_contour.is_empty() {
-        contours.push(Contour::new(current_contour, None));
+impl OutlinePen for GlyphPen {
+    fn move_to(&mut self, x: f64, y: f64) {
+        // Start new contour; the previous one ended without a `Z`.
+        if !self.current.is_empty() {
+            let contour = std::mem::take(&mut self.current);
+            self.contours.push((Contour::new(contour, None), false));
+        }
+        self.current
+            .push(ContourPoint::new(x, y, PointType::Curve, true, None, None));
+        self.current_point = (x, y);
     }
 
-    // Remove duplicate last point if it matches the first point
-    for contour in &mut contours {
-        if contour.points.len() > 1 {
-            let first = &contour.points[0];
-            let last = &contour.points[contour.points.len() - 1];
+    fn line_to(&mut self, x: f64, y: f64) {
+        self.current
+            .push(ContourPoint::new(x, y, PointType::Line, false, None, None));
+        self.current_point = (x, y);
+    }
 
-            if first.x == last.x && first.y == last.y {
-                contour.points.pop();
+    fn quad_to(&mut self, cx: f64, cy: f64, x: f64, y: f64) {
+        match self.quad_mode {
+            QuadMode::QCurve => {
+                // Native GLIF quadratic: off-curve control + qcurve endpoint.
+                self.current
+                    .push(ContourPoint::new(cx, cy, PointType::OffCurve, false, None, None));
+                self.current
+                    .push(ContourPoint::new(x, y, PointType::QCurve, true, None, None));
+            }
+            QuadMode::Cubic => {
+                // Exact quadratic→cubic equivalence (affine-invariant, so it
+                // holds in UFO space too):
+                // CP1 = P0 + 2/3·(Q − P0), CP2 = P2 + 2/3·(Q − P2).
+                let (p0x, p0y) = self.current_point;
+                let cp1x = p0x + 2.0 / 3.0 * (cx - p0x);
+                let cp1y = p0y + 2.0 / 3.0 * (cy - p0y);
+                let cp2x = x + 2.0 / 3.0 * (cx - x);
+                let cp2y = y + 2.0 / 3.0 * (cy - y);
+                self.curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
+                return;
             }
         }
+        self.current_point = (x, y);
     }
 
-    Ok(contours)
+    fn curve_to(&mut self, c1x: f64, c1y: f64, c2x: f64, c2y: f64, x: f64, y: f64) {
+        self.current
+            .push(ContourPoint::new(c1x, c1y, PointType::OffCurve, false, None, None));
+        self.current
+            .push(ContourPoint::new(c2x, c2y, PointType::OffCurve, false, None, None));
+        self.current
+            .push(ContourPoint::new(x, y, PointType::Curve, true, None, None));
+        self.current_point = (x, y);
+    }
+
+    fn close(&mut self) {
+        // Finish current contour (explicitly closed with `Z`).
+        if !self.current.is_empty() {
+            let contour = std::mem::take(&mut self.current);
+            self.contours.push((Contour::new(contour, None), true));
+        }
+    }
 }
 
 fn process_text_as_anchor(
@@ -404,9 +802,564 @@ fn process_text_as_anchor(
     Some(Anchor::new(ufo_x, ufo_y, Some(anchor_name), None, None))
 }
 
+/// Turn a `<use>` reference into a GLIF [`Component`], mirroring how composite
+/// glyphs reuse a base outline with an offset and 2×2 transform.
+///
+/// `svg_height`/`descent`/`scale` fix the coordinate frame this file was
+/// converted under; the referenced base glyph must have been converted under
+/// the *same* frame (a shared [`ConversionConfig::svg_height_override`], as
+/// [`FontConfig`] sets for every glyph in a batch) or the component's
+/// transform will be scaled and offset wrong whenever the two files declare
+/// different `height` attributes.
+fn process_use_as_component(
+    node: &roxmltree::Node,
+    svg_height: f32,
+    descent: f32,
+    scale: f32,
+    current_transform: &Transform,
+) -> Result<Option<Component>> {
+    // `href` may be namespaced as `xlink:href`; match on the local name.
+    let href = node
+        .attributes()
+        .find(|a| a.name() == "href")
+        .map(|a| a.value());
+    let Some(href) = href else {
+        return Ok(None);
+    };
+    let base = href.strip_prefix('#').unwrap_or(href);
+    let Ok(base) = Name::new(base) else {
+        return Ok(None);
+    };
+
+    // `<use x y>` is an extra translation applied inside the element's own
+    // transform, which `current_transform` already folded in.
+    let x = node
+        .attribute("x")
+        .and_then(|s| parse_length(s).ok())
+        .unwrap_or(0.0);
+    let y = node
+        .attribute("y")
+        .and_then(|s| parse_length(s).ok())
+        .unwrap_or(0.0);
+    let translate = Transform {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        e: x as f64,
+        f: y as f64,
+    };
+    let svg_transform = multiply(current_transform, &translate);
+
+    // Conjugate the SVG-space transform into UFO space: T_ufo = F·M·F⁻¹, where
+    // F is the same Y-flip/scale `svg_to_ufo` applies to outline points.
+    let k = (svg_height - descent) as f64 * scale as f64;
+    let f_fwd = Transform {
+        a: scale as f64,
+        b: 0.0,
+        c: 0.0,
+        d: -(scale as f64),
+        e: 0.0,
+        f: k,
+    };
+    let f_inv = Transform {
+        a: 1.0 / scale as f64,
+        b: 0.0,
+        c: 0.0,
+        d: -1.0 / scale as f64,
+        e: 0.0,
+        f: k / scale as f64,
+    };
+    let ufo = multiply(&f_fwd, &multiply(&svg_transform, &f_inv));
+
+    let transform = AffineTransform {
+        x_scale: ufo.a,
+        xy_scale: ufo.b,
+        yx_scale: ufo.c,
+        y_scale: ufo.d,
+        x_offset: ufo.e,
+        y_offset: ufo.f,
+    };
+
+    Ok(Some(Component::new(base, transform, None)))
+}
+
 fn svg_to_ufo(sx: f32, sy: f32, svg_height: f32, descent: f32, scale: f32) -> (f64, f64) {
     // Flip Y (SVG origin is top-left; UFO origin baseline is bottom-left)
     let x = sx * scale;
     let y = (svg_height - descent - sy) * scale;
     (x.round() as f64, y.round() as f64)
 }
+
+// --- Stroke-to-fill subsystem ---------------------------------------------
+//
+// Converts stroked SVG geometry into filled GLIF contours by offsetting each
+// contour to ±width/2 along its segment normals, joining offset segments with
+// the configured join style and closing open contours with the configured
+// cap style. Curves are flattened to a polyline first; the resulting outlines
+// are emitted as `line` contours, which downstream overlap-removal tooling can
+// simplify further.
+
+/// True when the element carries a visible `stroke`.
+fn node_has_stroke(node: &roxmltree::Node) -> bool {
+    matches!(node.attribute("stroke"), Some(s) if s != "none")
+}
+
+/// Apply the SVG presentation attributes as overrides on top of the default
+/// stroke style.
+fn resolve_stroke_style(node: &roxmltree::Node, default: &StrokeStyle) -> StrokeStyle {
+    let mut style = *default;
+    match node.attribute("stroke-linejoin") {
+        Some("miter") => style.join = LineJoin::Miter,
+        Some("round") => style.join = LineJoin::Round,
+        Some("bevel") => style.join = LineJoin::Bevel,
+        _ => {}
+    }
+    match node.attribute("stroke-linecap") {
+        Some("butt") => style.cap = LineCap::Butt,
+        Some("round") => style.cap = LineCap::Round,
+        Some("square") => style.cap = LineCap::Square,
+        _ => {}
+    }
+    if let Some(limit) = node
+        .attribute("stroke-miterlimit")
+        .and_then(|s| s.parse::<f32>().ok())
+    {
+        style.miter_limit = limit;
+    }
+    style
+}
+
+/// Distance-equality test for flattened outline points (in font units).
+fn points_close(a: (f64, f64), b: (f64, f64)) -> bool {
+    (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6
+}
+
+/// Flatten a contour's on/off-curve points into a polyline in font units.
+fn flatten_contour(contour: &Contour) -> Vec<(f64, f64)> {
+    let pts = &contour.points;
+    let n = pts.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Start from the first on-curve point so leading off-curves are handled.
+    let start = pts
+        .iter()
+        .position(|p| p.typ != PointType::OffCurve)
+        .unwrap_or(0);
+    let first = (pts[start].x, pts[start].y);
+    let mut out = vec![first];
+    let mut prev = first;
+    let mut pending: Vec<(f64, f64)> = Vec::new();
+
+    for k in 1..=n {
+        let p = &pts[(start + k) % n];
+        let pt = (p.x, p.y);
+        if p.typ == PointType::OffCurve {
+            pending.push(pt);
+        } else {
+            flatten_segment(&mut out, prev, &pending, pt);
+            pending.clear();
+            prev = pt;
+        }
+    }
+
+    // The wrap-around segment re-emits the first vertex; drop the duplicate.
+    if out.len() > 1 && points_close(out[0], out[out.len() - 1]) {
+        out.pop();
+    }
+    out
+}
+
+/// Append a flattened line/quadratic/cubic segment (excluding its start point).
+fn flatten_segment(out: &mut Vec<(f64, f64)>, p0: (f64, f64), ctrls: &[(f64, f64)], p1: (f64, f64)) {
+    const STEPS: usize = 12;
+    match ctrls.len() {
+        0 => out.push(p1),
+        1 => {
+            let c = ctrls[0];
+            for i in 1..=STEPS {
+                let t = i as f64 / STEPS as f64;
+                let mt = 1.0 - t;
+                let x = mt * mt * p0.0 + 2.0 * mt * t * c.0 + t * t * p1.0;
+                let y = mt * mt * p0.1 + 2.0 * mt * t * c.1 + t * t * p1.1;
+                out.push((x, y));
+            }
+        }
+        _ => {
+            let (c1, c2) = (ctrls[0], ctrls[1]);
+            for i in 1..=STEPS {
+                let t = i as f64 / STEPS as f64;
+                let mt = 1.0 - t;
+                let x = mt * mt * mt * p0.0
+                    + 3.0 * mt * mt * t * c1.0
+                    + 3.0 * mt * t * t * c2.0
+                    + t * t * t * p1.0;
+                let y = mt * mt * mt * p0.1
+                    + 3.0 * mt * mt * t * c1.1
+                    + 3.0 * mt * t * t * c2.1
+                    + t * t * t * p1.1;
+                out.push((x, y));
+            }
+        }
+    }
+}
+
+/// Stroke a single contour into one or more filled contours.
+fn stroke_contour(contour: &Contour, closed: bool, width: f64, style: &StrokeStyle) -> Vec<Contour> {
+    let half = width / 2.0;
+    let mut pts = flatten_contour(contour);
+    pts.dedup_by(|a, b| points_close(*a, *b));
+    if pts.len() > 1 && points_close(pts[0], pts[pts.len() - 1]) {
+        pts.pop();
+    }
+    if pts.len() < 2 {
+        return Vec::new();
+    }
+
+    if closed {
+        // Outer ring plus an inner ring with opposite winding (the hole).
+        let outer = offset_side(&pts, true, half, style.join, style.miter_limit);
+        let mut inner = offset_side(&pts, true, -half, style.join, style.miter_limit);
+        inner.reverse();
+        vec![polygon_to_contour(&outer), polygon_to_contour(&inner)]
+    } else {
+        // Single closed outline: left side, end cap, right side, start cap.
+        let left = offset_side(&pts, false, half, style.join, style.miter_limit);
+        let right = offset_side(&pts, false, -half, style.join, style.miter_limit);
+        let mut ring = left;
+        let n = pts.len();
+        add_cap(&mut ring, pts[n - 1], pts[n - 2], half, style.cap);
+        ring.extend(right.iter().rev().copied());
+        add_cap(&mut ring, pts[0], pts[1], half, style.cap);
+        vec![polygon_to_contour(&ring)]
+    }
+}
+
+/// Offset one side of a polyline by `d` (signed), inserting joins at vertices.
+fn offset_side(
+    points: &[(f64, f64)],
+    closed: bool,
+    d: f64,
+    join: LineJoin,
+    miter_limit: f32,
+) -> Vec<(f64, f64)> {
+    let m = points.len();
+    if m < 2 {
+        return Vec::new();
+    }
+    let seg_count = if closed { m } else { m - 1 };
+
+    // Unit normals pointing to the left of each segment's travel direction.
+    let mut normals = Vec::with_capacity(seg_count);
+    for i in 0..seg_count {
+        let a = points[i];
+        let b = points[(i + 1) % m];
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len = (dx * dx + dy * dy).sqrt();
+        normals.push(if len < 1e-9 {
+            (0.0, 0.0)
+        } else {
+            (-dy / len, dx / len)
+        });
+    }
+
+    let mut out = Vec::new();
+    if closed {
+        for i in 0..m {
+            let prev = (i + m - 1) % m;
+            add_join(&mut out, points[i], normals[prev], normals[i], d, join, miter_limit);
+        }
+    } else {
+        out.push((points[0].0 + d * normals[0].0, points[0].1 + d * normals[0].1));
+        for i in 1..m - 1 {
+            add_join(&mut out, points[i], normals[i - 1], normals[i], d, join, miter_limit);
+        }
+        let last = m - 1;
+        out.push((
+            points[last].0 + d * normals[last - 1].0,
+            points[last].1 + d * normals[last - 1].1,
+        ));
+    }
+    out
+}
+
+/// Insert the join geometry between two offset segments meeting at `pivot`.
+fn add_join(
+    out: &mut Vec<(f64, f64)>,
+    pivot: (f64, f64),
+    n_prev: (f64, f64),
+    n_cur: (f64, f64),
+    d: f64,
+    join: LineJoin,
+    miter_limit: f32,
+) {
+    let off_prev = (pivot.0 + d * n_prev.0, pivot.1 + d * n_prev.1);
+    let off_cur = (pivot.0 + d * n_cur.0, pivot.1 + d * n_cur.1);
+    if points_close(off_prev, off_cur) {
+        out.push(off_prev);
+        return;
+    }
+
+    match join {
+        LineJoin::Bevel => {
+            out.push(off_prev);
+            out.push(off_cur);
+        }
+        LineJoin::Miter => {
+            let (mx, my) = (n_prev.0 + n_cur.0, n_prev.1 + n_cur.1);
+            let mlen = (mx * mx + my * my).sqrt();
+            let cos_half = mlen / 2.0;
+            if mlen < 1e-9 || cos_half < 1e-6 || (1.0 / cos_half) as f32 > miter_limit {
+                out.push(off_prev);
+                out.push(off_cur);
+            } else {
+                let (ux, uy) = (mx / mlen, my / mlen);
+                let miter_len = d / cos_half;
+                out.push((pivot.0 + ux * miter_len, pivot.1 + uy * miter_len));
+            }
+        }
+        LineJoin::Round => {
+            out.push(off_prev);
+            arc_points(out, pivot, off_prev, off_cur, d.abs());
+            out.push(off_cur);
+        }
+    }
+}
+
+/// Sample the intermediate points of an arc from `from` to `to` around `center`.
+fn arc_points(
+    out: &mut Vec<(f64, f64)>,
+    center: (f64, f64),
+    from: (f64, f64),
+    to: (f64, f64),
+    radius: f64,
+) {
+    use std::f64::consts::PI;
+    let a0 = (from.1 - center.1).atan2(from.0 - center.0);
+    let a1 = (to.1 - center.1).atan2(to.0 - center.0);
+    let mut da = a1 - a0;
+    while da > PI {
+        da -= 2.0 * PI;
+    }
+    while da < -PI {
+        da += 2.0 * PI;
+    }
+    let steps = ((da.abs() / (PI / 8.0)).ceil() as usize).max(1);
+    for i in 1..steps {
+        let a = a0 + da * (i as f64 / steps as f64);
+        out.push((center.0 + radius * a.cos(), center.1 + radius * a.sin()));
+    }
+}
+
+/// Close an open stroke end at `end` (previous point `prev`) with `cap`.
+fn add_cap(out: &mut Vec<(f64, f64)>, end: (f64, f64), prev: (f64, f64), half: f64, cap: LineCap) {
+    use std::f64::consts::PI;
+    let (mut tx, mut ty) = (end.0 - prev.0, end.1 - prev.1);
+    let len = (tx * tx + ty * ty).sqrt();
+    if len < 1e-9 {
+        return;
+    }
+    tx /= len;
+    ty /= len;
+    let (nx, ny) = (-ty, tx);
+
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Square => {
+            let left = (end.0 + half * nx, end.1 + half * ny);
+            let right = (end.0 - half * nx, end.1 - half * ny);
+            out.push((left.0 + half * tx, left.1 + half * ty));
+            out.push((right.0 + half * tx, right.1 + half * ty));
+        }
+        LineCap::Round => {
+            // Semicircle bulging in the travel direction, from left to right.
+            let a0 = ty.atan2(tx) + PI / 2.0;
+            let steps = 8;
+            for i in 1..steps {
+                let a = a0 - PI * (i as f64 / steps as f64);
+                out.push((end.0 + half * a.cos(), end.1 + half * a.sin()));
+            }
+        }
+    }
+}
+
+/// Build a closed `line` contour from a polygon of font-unit points.
+fn polygon_to_contour(points: &[(f64, f64)]) -> Contour {
+    let points = points
+        .iter()
+        .map(|&(x, y)| {
+            ContourPoint::new(x.round(), y.round(), PointType::Line, false, None, None)
+        })
+        .collect();
+    Contour::new(points, None)
+}
+
+// --- Winding normalization -------------------------------------------------
+//
+// UFO/GLIF fill and downstream tools (fontmake, overlap removal) expect a
+// consistent winding convention. We classify each contour by how many other
+// contours enclose it — an odd enclosure count marks a hole — and reverse any
+// contour whose orientation disagrees with the convention: outer contours run
+// counterclockwise, holes clockwise. This matches ufo2ft/fontmake's default
+// CFF/PostScript output convention.
+
+/// On-curve points of a contour, used for area and containment tests.
+fn on_curve_points(contour: &Contour) -> Vec<(f64, f64)> {
+    contour
+        .points
+        .iter()
+        .filter(|p| p.typ != PointType::OffCurve)
+        .map(|p| (p.x, p.y))
+        .collect()
+}
+
+/// Signed area of a polygon via the shoelace formula. Positive is
+/// counterclockwise in UFO's y-up space.
+fn signed_area(pts: &[(f64, f64)]) -> f64 {
+    let n = pts.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let mut sum = 0.0;
+    for i in 0..n {
+        let (x0, y0) = pts[i];
+        let (x1, y1) = pts[(i + 1) % n];
+        sum += x0 * y1 - x1 * y0;
+    }
+    sum / 2.0
+}
+
+/// Even-odd point-in-polygon test (ray casting).
+fn point_in_polygon(pt: (f64, f64), poly: &[(f64, f64)]) -> bool {
+    let n = poly.len();
+    if n < 3 {
+        return false;
+    }
+    let (px, py) = pt;
+    let mut inside = false;
+    let mut j = n - 1;
+    for i in 0..n {
+        let (xi, yi) = poly[i];
+        let (xj, yj) = poly[j];
+        if (yi > py) != (yj > py) {
+            let x_cross = (xj - xi) * (py - yi) / (yj - yi) + xi;
+            if px < x_cross {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Reverse a contour's point order while preserving GLIF segment semantics
+/// (off-curve runs and the on-curve type that terminates each segment).
+fn reverse_contour(contour: &Contour) -> Contour {
+    let pts = &contour.points;
+    let n = pts.len();
+    if n < 2 {
+        return contour.clone();
+    }
+
+    // Split into segments starting at the first on-curve point: each segment
+    // is the run of off-curve control points followed by its on-curve endpoint.
+    let start = pts
+        .iter()
+        .position(|p| p.typ != PointType::OffCurve)
+        .unwrap_or(0);
+    let mut controls: Vec<Vec<&ContourPoint>> = Vec::new();
+    let mut types: Vec<PointType> = Vec::new();
+    let mut endpoints: Vec<&ContourPoint> = Vec::new();
+    let mut pending: Vec<&ContourPoint> = Vec::new();
+    for k in 0..n {
+        let p = &pts[(start + k) % n];
+        if p.typ == PointType::OffCurve {
+            pending.push(p);
+        } else {
+            controls.push(std::mem::take(&mut pending));
+            types.push(p.typ);
+            endpoints.push(p);
+        }
+    }
+    // Off-curves past the last on-curve wrap back onto the start point.
+    if !pending.is_empty() {
+        controls[0] = pending;
+    }
+
+    let m = endpoints.len();
+    if m < 2 {
+        return contour.clone();
+    }
+
+    let clone_as = |p: &ContourPoint, typ: PointType| {
+        ContourPoint::new(p.x, p.y, typ, p.smooth, None, None)
+    };
+
+    let mut out: Vec<ContourPoint> = Vec::new();
+    // Reversed start keeps endpoint 0 but inherits the next segment's type.
+    out.push(clone_as(endpoints[0], types[1 % m]));
+    for k in 1..m {
+        let old = (m - k + 1) % m;
+        for c in controls[old].iter().rev() {
+            out.push(clone_as(c, PointType::OffCurve));
+        }
+        out.push(clone_as(endpoints[m - k], types[old]));
+    }
+    // Controls of the wrap-around segment trail the point list.
+    for c in controls[1 % m].iter().rev() {
+        out.push(clone_as(c, PointType::OffCurve));
+    }
+
+    Contour::new(out, None)
+}
+
+/// Reverse contours in place so outer outlines run counterclockwise and
+/// holes clockwise, matching ufo2ft/fontmake's default CFF/PostScript
+/// convention.
+fn normalize_winding(glyph: &mut Glyph) {
+    let polys: Vec<Vec<(f64, f64)>> = glyph.contours.iter().map(on_curve_points).collect();
+
+    for i in 0..glyph.contours.len() {
+        if polys[i].len() < 3 {
+            continue;
+        }
+        // A contour is a hole when enclosed by an odd number of others.
+        let probe = polys[i][0];
+        let depth = polys
+            .iter()
+            .enumerate()
+            .filter(|(j, poly)| *j != i && point_in_polygon(probe, poly))
+            .count();
+
+        let area = signed_area(&polys[i]);
+        // Outer (even depth) wants counterclockwise (area > 0); holes want the opposite.
+        let want_counterclockwise = depth % 2 == 0;
+        let is_counterclockwise = area > 0.0;
+        if want_counterclockwise != is_counterclockwise {
+            glyph.contours[i] = reverse_contour(&glyph.contours[i]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_quadratic_contour_retypes_start_point() {
+        let svg = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg width="10" height="10" xmlns="http://www.w3.org/2000/svg">
+  <path d="M0 0 Q5 5 10 0 Q5 -5 0 0 Z"/>
+</svg>"#;
+        let config = ConversionConfig::new(10.0, 0.0);
+        let glyph =
+            convert_svg_string_to_glyph(svg, Path::new("closed_quad.svg"), &config).unwrap();
+
+        let contour = &glyph.contours[0];
+        let points = &contour.points;
+        assert_eq!(points[0].typ, PointType::QCurve);
+        assert_eq!(points[points.len() - 1].typ, PointType::OffCurve);
+    }
+}